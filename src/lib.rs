@@ -77,6 +77,304 @@ impl<'a> HttpUri<'a> {
         })
     }
 
+    /// Split this URI's authority into its userinfo, host, and port components
+    /// [RFC3986§3.2].
+    pub fn authority_parts(&self) -> Result<HttpAuthority<'a>, ()> {
+        HttpAuthority::new(self.authority)
+    }
+
+    /// Produce the canonical syntax-based normalization of this URI
+    /// [RFC3986§6](https://tools.ietf.org/html/rfc3986#section-6).
+    ///
+    /// This lowercases the host, drops a port that is the default for the scheme, and
+    /// removes `.`/`..` path segments. It does not decode the resource body, so it is
+    /// only as canonical as the two URIs being compared are equivalent without
+    /// decoding.
+    pub fn normalize(&self) -> Result<NormalizedHttpUri, ()> {
+        let mut s = String::new();
+        self.write_normalized(&mut s).map_err(|_| ())?;
+        Ok(NormalizedHttpUri(s))
+    }
+
+    /// Stream the [`normalize`](#method.normalize)d form of this URI to the given
+    /// writer.
+    pub fn write_normalized<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        let authority = self.authority_parts().map_err(|_| std::fmt::Error)?;
+
+        write!(w, "{}://", self.scheme)?;
+
+        if let Some(userinfo) = authority.userinfo {
+            write!(w, "{}@", userinfo)?;
+        }
+
+        for c in authority.host.chars() {
+            write!(w, "{}", c.to_ascii_lowercase())?;
+        }
+
+        if let Some(port) = authority.port {
+            if !is_default_port(self.scheme, port) {
+                write!(w, ":{}", port)?;
+            }
+        }
+
+        w.write_str(&remove_dot_segments(self.resource.path))?;
+
+        if let Some(q) = self.resource.query {
+            write!(w, "?{}", q)?;
+        }
+
+        if let Some(f) = self.resource.fragment {
+            write!(w, "#{}", f)?;
+        }
+
+        Ok(())
+    }
+
+    /// Resolve a relative reference against this URI as the base, per
+    /// [RFC3986§5.3](https://tools.ietf.org/html/rfc3986#section-5.3).
+    pub fn resolve(&self, reference: &str) -> Result<OwnedHttpUri, ()> {
+        let has_scheme = match reference.find("://") {
+            Some(idx) => !reference[..idx].contains(|c| c == '/' || c == '?' || c == '#'),
+            None => false,
+        };
+
+        if has_scheme {
+            let target = HttpUri::new(reference)?;
+
+            return build_owned(target.scheme, target.authority,
+                &remove_dot_segments(target.resource.path), target.resource.query,
+                target.resource.fragment);
+        }
+
+        if reference.starts_with("//") {
+            let rest = &reference[2..];
+
+            let (authority, rest) = match rest.find('/') {
+                Some(idx) => rest.split_at(idx),
+                None => (rest, ""),
+            };
+
+            if authority.is_empty() {
+                return Err(());
+            }
+
+            let resource = HttpResource::new(rest);
+
+            return build_owned(self.scheme, authority, &remove_dot_segments(resource.path),
+                resource.query, resource.fragment);
+        }
+
+        let (raw_path, _, _) = parts(reference, reference.find('?'), reference.find('#'));
+        let resource = HttpResource::new(reference);
+
+        let (path, query) = if raw_path.is_empty() {
+            (self.resource.path.to_string(), resource.query.or(self.resource.query))
+        } else if raw_path.starts_with('/') {
+            (remove_dot_segments(raw_path), resource.query)
+        } else {
+            (remove_dot_segments(&merge_paths(self.resource.path, raw_path)), resource.query)
+        };
+
+        build_owned(self.scheme, self.authority, &path, query, resource.fragment)
+    }
+}
+
+/// Join a relative-reference path onto a base path's directory, per the "merge" step of
+/// [RFC3986§5.3](https://tools.ietf.org/html/rfc3986#section-5.3).
+fn merge_paths(base_path: &str, ref_path: &str) -> String {
+    match base_path.rfind('/') {
+        Some(idx) => format!("{}{}", &base_path[..idx + 1], ref_path),
+        None => format!("/{}", ref_path),
+    }
+}
+
+/// Assemble a validated [`OwnedHttpUri`] from its already-split-out components.
+fn build_owned(scheme: HttpScheme, authority: &str, path: &str, query: Option<&str>,
+    fragment: Option<&str>) -> Result<OwnedHttpUri, ()>
+{
+    let mut builder = HttpUriBuilder::new().scheme(scheme).authority(authority).path(path);
+
+    if let Some(q) = query {
+        builder = builder.query(q);
+    }
+
+    if let Some(f) = fragment {
+        builder = builder.fragment(f);
+    }
+
+    builder.build()
+}
+
+/// Whether `port` is the default port for `scheme`, and so may be elided when
+/// normalizing.
+fn is_default_port(scheme: HttpScheme, port: &str) -> bool {
+    match scheme {
+        HttpScheme::Http => port == "80",
+        HttpScheme::Https => port == "443",
+    }
+}
+
+/// Apply the "remove dot segments" algorithm to a path
+/// [RFC3986§5.2.4](https://tools.ietf.org/html/rfc3986#section-5.2.4).
+fn remove_dot_segments(path: &str) -> String {
+    let mut input = path;
+    let mut output = String::new();
+
+    while !input.is_empty() {
+        if input.starts_with("../") {
+            input = &input[3..];
+        } else if input.starts_with("./") {
+            input = &input[2..];
+        } else if input.starts_with("/./") {
+            input = &input[2..];
+        } else if input == "/." {
+            input = "/";
+        } else if input.starts_with("/../") {
+            input = &input[3..];
+            pop_last_segment(&mut output);
+        } else if input == "/.." {
+            input = "/";
+            pop_last_segment(&mut output);
+        } else if input == "." || input == ".." {
+            input = "";
+        } else {
+            let rest = if input.starts_with('/') { &input[1..] } else { input };
+            let seg_len = rest.find('/').map_or(rest.len(), |idx| idx) + (input.len() - rest.len());
+
+            output.push_str(&input[..seg_len]);
+            input = &input[seg_len..];
+        }
+    }
+
+    output
+}
+
+/// Drop the last `/`-delimited segment from an in-progress `remove_dot_segments` output,
+/// as required when an input `..` segment is encountered.
+fn pop_last_segment(output: &mut String) {
+    match output.rfind('/') {
+        Some(idx) => output.truncate(idx),
+        None => output.clear(),
+    }
+}
+
+/// An owned, syntax-based normalized rendering of an [`HttpUri`], as produced by
+/// [`HttpUri::normalize`].
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct NormalizedHttpUri(String);
+
+impl std::fmt::Display for NormalizedHttpUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::ops::Deref for NormalizedHttpUri {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// An owned HTTP URI, as produced by [`HttpUriBuilder`] or [`HttpUri::resolve`].
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct OwnedHttpUri(String);
+
+impl OwnedHttpUri {
+    /// Borrow this URI's components as an [`HttpUri`].
+    pub fn as_http_uri(&self) -> HttpUri {
+        HttpUri::new(&self.0).expect("OwnedHttpUri must always contain a valid HttpUri")
+    }
+}
+
+impl std::fmt::Display for OwnedHttpUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::ops::Deref for OwnedHttpUri {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Incrementally builds an [`OwnedHttpUri`] from its piecewise scheme, authority, path,
+/// query, and fragment components, à la the `http` crate's `uri::Builder`.
+#[derive(Clone, Debug, Default)]
+pub struct HttpUriBuilder<'a> {
+    scheme: Option<HttpScheme>,
+    authority: Option<&'a str>,
+    path: Option<&'a str>,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+impl<'a> HttpUriBuilder<'a> {
+    /// Create a new, empty builder.
+    pub fn new() -> Self {
+        HttpUriBuilder::default()
+    }
+
+    /// Set the URI's scheme.
+    pub fn scheme(mut self, scheme: HttpScheme) -> Self {
+        self.scheme = Some(scheme);
+        self
+    }
+
+    /// Set the URI's authority.
+    pub fn authority(mut self, authority: &'a str) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+
+    /// Set the URI's path. Defaults to `"/"` if never set.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Set the URI's query.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Set the URI's fragment.
+    pub fn fragment(mut self, fragment: &'a str) -> Self {
+        self.fragment = Some(fragment);
+        self
+    }
+
+    /// Validate the accumulated components and assemble an [`OwnedHttpUri`].
+    pub fn build(self) -> Result<OwnedHttpUri, ()> {
+        let scheme = self.scheme.ok_or(())?;
+        let authority = self.authority.ok_or(())?;
+        let path = self.path.unwrap_or("/");
+
+        if !path.starts_with('/') {
+            return Err(());
+        }
+
+        let mut s = format!("{}://{}{}", scheme, authority, path);
+
+        if let Some(q) = self.query {
+            s.push('?');
+            s.push_str(q);
+        }
+
+        if let Some(f) = self.fragment {
+            s.push('#');
+            s.push_str(f);
+        }
+
+        HttpUri::new(&s).map_err(|_| ())?;
+
+        Ok(OwnedHttpUri(s))
+    }
 }
 
 /// Writes the URI in the format required by [RFC7230§2.7.1]/[RFC7230§2.7.2].
@@ -86,6 +384,89 @@ impl<'a> std::fmt::Display for HttpUri<'a> {
     }
 }
 
+/// Components in an HTTP URI authority [RFC3986§3.2].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct HttpAuthority<'a> {
+    /// Userinfo subcomponent, if present.
+    pub userinfo: Option<&'a str>,
+
+    /// Host subcomponent.
+    ///
+    /// This is a domain name, an IPv4 address, or a bracketed IPv6 literal such as
+    /// `[::1]`. The brackets are kept as part of this slice.
+    pub host: &'a str,
+
+    /// Port subcomponent, if present.
+    pub port: Option<&'a str>,
+}
+
+impl<'a> HttpAuthority<'a> {
+    /// Parse the given authority string into its userinfo, host, and port components.
+    pub fn new(s: &'a str) -> Result<Self, ()> {
+        let (userinfo, rest) = match s.find('@') {
+            Some(idx) => {
+                let (userinfo, rest) = s.split_at(idx);
+                (Some(userinfo), &rest[1..])
+            },
+            None => (None, s),
+        };
+
+        let (host, port) = if rest.starts_with('[') {
+            let idx = match rest.find(']') {
+                Some(idx) => idx,
+                None => return Err(()),
+            };
+
+            let (host, rest) = rest.split_at(idx + 1);
+
+            let port = if rest.is_empty() {
+                None
+            } else if rest.starts_with(':') {
+                Some(&rest[1..])
+            } else {
+                return Err(());
+            };
+
+            (host, port)
+        } else {
+            match rest.rfind(':') {
+                Some(idx) => {
+                    let (host, port) = rest.split_at(idx);
+                    (host, Some(&port[1..]))
+                },
+                None => (rest, None),
+            }
+        };
+
+        if host.is_empty() {
+            return Err(());
+        }
+
+        Ok(HttpAuthority {
+            userinfo: userinfo,
+            host: host,
+            port: port,
+        })
+    }
+}
+
+/// Writes the authority in the format required by [RFC3986§3.2].
+impl<'a> std::fmt::Display for HttpAuthority<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(userinfo) = self.userinfo {
+            write!(f, "{}@", userinfo)?;
+        }
+
+        f.write_str(self.host)?;
+
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+
+        Ok(())
+    }
+}
+
 /// Components in an HTTP URI resource.
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
 pub struct HttpResource<'a> {
@@ -124,6 +505,16 @@ impl<'a> HttpResource<'a> {
             }
         }
     }
+
+    /// Iterate over the `&`- or `;`-delimited key/value pairs in the query string, if any.
+    ///
+    /// Each pair is split once on its first `=`, yielding `None` for a key with no `=`.
+    /// Neither the key nor the value is percent-decoded.
+    pub fn query_pairs(&self) -> QueryPairs<'a> {
+        QueryPairs {
+            rest: self.query,
+        }
+    }
 }
 
 impl<'a> std::fmt::Display for HttpResource<'a> {
@@ -142,6 +533,42 @@ impl<'a> std::fmt::Display for HttpResource<'a> {
     }
 }
 
+/// Iterator over the key/value pairs in a query string, as produced by
+/// [`HttpResource::query_pairs`](struct.HttpResource.html#method.query_pairs).
+#[derive(Clone, Debug)]
+pub struct QueryPairs<'a> {
+    rest: Option<&'a str>,
+}
+
+impl<'a> Iterator for QueryPairs<'a> {
+    type Item = (&'a str, Option<&'a str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = match self.rest {
+            Some(s) => s,
+            None => return None,
+        };
+
+        let (pair, rest) = match s.find(|c| c == '&' || c == ';') {
+            Some(idx) => {
+                let (pair, rest) = s.split_at(idx);
+                (pair, Some(&rest[1..]))
+            },
+            None => (s, None),
+        };
+
+        self.rest = rest;
+
+        Some(match pair.find('=') {
+            Some(idx) => {
+                let (key, val) = pair.split_at(idx);
+                (key, Some(&val[1..]))
+            },
+            None => (pair, None),
+        })
+    }
+}
+
 /// Split URI into path, query, and fragment [RFC3986§3].
 fn parts<'a>(s: &'a str, qidx: Option<usize>, fidx: Option<usize>)
     -> (&'a str, &'a str, &'a str)
@@ -199,6 +626,41 @@ impl std::fmt::Display for HttpScheme {
     }
 }
 
+/// The target of an HTTP request line, in any of the four forms given by
+/// [RFC7230§5.3](https://tools.ietf.org/html/rfc7230#section-5.3).
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum RequestTarget<'a> {
+    /// origin-form — an absolute path and optional query, used by most requests.
+    Origin(HttpResource<'a>),
+    /// absolute-form — a full URI, used for requests made through a proxy.
+    Absolute(HttpUri<'a>),
+    /// authority-form — a bare `host:port`, used for `CONNECT` requests.
+    ///
+    /// This is passed through as written; use [`HttpAuthority::new`] to further split
+    /// it into userinfo, host, and port.
+    Authority(&'a str),
+    /// asterisk-form — a literal `*`, used for server-wide `OPTIONS` requests.
+    Asterisk,
+}
+
+impl<'a> RequestTarget<'a> {
+    /// Parse the given request-target string into whichever of the four
+    /// RFC7230§5.3 forms it matches.
+    pub fn new(s: &'a str) -> Result<Self, ()> {
+        if s == "*" {
+            Ok(RequestTarget::Asterisk)
+        } else if s.starts_with('/') {
+            Ok(RequestTarget::Origin(HttpResource::new(s)))
+        } else if s.contains("://") {
+            HttpUri::new(s).map(RequestTarget::Absolute)
+        } else if s.is_empty() {
+            Err(())
+        } else {
+            Ok(RequestTarget::Authority(s))
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -359,4 +821,238 @@ mod test {
                 }
             });
     }
+
+    #[test]
+    fn test_http_authority() {
+        assert_eq!(HttpAuthority::new("example.com").unwrap(),
+            HttpAuthority {
+                userinfo: None,
+                host: "example.com",
+                port: None,
+            });
+
+        assert_eq!(HttpAuthority::new("example.com:8080").unwrap(),
+            HttpAuthority {
+                userinfo: None,
+                host: "example.com",
+                port: Some("8080"),
+            });
+
+        assert_eq!(HttpAuthority::new("user:pass@example.com:8080").unwrap(),
+            HttpAuthority {
+                userinfo: Some("user:pass"),
+                host: "example.com",
+                port: Some("8080"),
+            });
+
+        assert_eq!(HttpAuthority::new("user@[::1]:8080").unwrap(),
+            HttpAuthority {
+                userinfo: Some("user"),
+                host: "[::1]",
+                port: Some("8080"),
+            });
+
+        assert_eq!(HttpAuthority::new("[::1]").unwrap(),
+            HttpAuthority {
+                userinfo: None,
+                host: "[::1]",
+                port: None,
+            });
+
+        assert_eq!(HttpAuthority::new("127.0.0.1:61761").unwrap(),
+            HttpAuthority {
+                userinfo: None,
+                host: "127.0.0.1",
+                port: Some("61761"),
+            });
+
+        assert!(HttpAuthority::new("").is_err());
+        assert!(HttpAuthority::new("user@").is_err());
+        assert!(HttpAuthority::new("[::1").is_err());
+        assert!(HttpAuthority::new("[::1]x").is_err());
+
+        assert_eq!(HttpUri::new("http://user@[::1]:8080/chunks").unwrap().authority_parts().unwrap(),
+            HttpAuthority {
+                userinfo: Some("user"),
+                host: "[::1]",
+                port: Some("8080"),
+            });
+    }
+
+    #[test]
+    fn test_request_target() {
+        assert_eq!(RequestTarget::new("*").unwrap(), RequestTarget::Asterisk);
+
+        assert_eq!(RequestTarget::new("/chunks?a=1").unwrap(),
+            RequestTarget::Origin(HttpResource {
+                path: "/chunks",
+                query: Some("a=1"),
+                fragment: None,
+            }));
+
+        assert_eq!(RequestTarget::new("http://example.com/chunks").unwrap(),
+            RequestTarget::Absolute(HttpUri {
+                scheme: HttpScheme::Http,
+                authority: "example.com",
+                resource: HttpResource {
+                    path: "/chunks",
+                    query: None,
+                    fragment: None,
+                },
+            }));
+
+        assert_eq!(RequestTarget::new("example.com:443").unwrap(),
+            RequestTarget::Authority("example.com:443"));
+
+        // authority-form is passed through even when it isn't a valid `HttpAuthority`.
+        assert_eq!(RequestTarget::new("[::1").unwrap(), RequestTarget::Authority("[::1"));
+
+        assert!(RequestTarget::new("").is_err());
+    }
+
+    #[test]
+    fn test_query_pairs() {
+        assert_eq!(HttpResource::new("/a").query_pairs().collect::<Vec<_>>(),
+            vec![]);
+
+        assert_eq!(HttpResource::new("/a?k=v").query_pairs().collect::<Vec<_>>(),
+            vec![("k", Some("v"))]);
+
+        assert_eq!(HttpResource::new("/a?k=v&flag&j=w").query_pairs().collect::<Vec<_>>(),
+            vec![("k", Some("v")), ("flag", None), ("j", Some("w"))]);
+
+        assert_eq!(HttpResource::new("/a?k=v;j=w").query_pairs().collect::<Vec<_>>(),
+            vec![("k", Some("v")), ("j", Some("w"))]);
+
+        assert_eq!(HttpResource::new("/a?k=v=w").query_pairs().collect::<Vec<_>>(),
+            vec![("k", Some("v=w"))]);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(HttpUri::new("http://Example.COM:80/a").unwrap().normalize().unwrap().to_string(),
+            "http://example.com/a");
+
+        assert_eq!(HttpUri::new("https://Example.COM:443/a").unwrap().normalize().unwrap().to_string(),
+            "https://example.com/a");
+
+        assert_eq!(HttpUri::new("http://Example.COM:8080/a").unwrap().normalize().unwrap().to_string(),
+            "http://example.com:8080/a");
+
+        assert_eq!(HttpUri::new("http://example.com/a/b/../c").unwrap().normalize().unwrap().to_string(),
+            "http://example.com/a/c");
+
+        assert_eq!(HttpUri::new("http://example.com/a/./b/").unwrap().normalize().unwrap().to_string(),
+            "http://example.com/a/b/");
+
+        assert_eq!(HttpUri::new("http://example.com").unwrap().normalize().unwrap().to_string(),
+            "http://example.com/");
+
+        assert_eq!(HttpUri::new("http://user@example.com:80/a?k=v#f").unwrap().normalize().unwrap().to_string(),
+            "http://user@example.com/a?k=v#f");
+
+        assert_eq!(HttpUri::new("http://example.com/a/b/.").unwrap().normalize().unwrap().to_string(),
+            "http://example.com/a/b/");
+
+        assert_eq!(HttpUri::new("http://example.com/a/b/..").unwrap().normalize().unwrap().to_string(),
+            "http://example.com/a/");
+
+        assert_eq!(HttpUri::new("http://example.com/a/b/./").unwrap().normalize().unwrap().to_string(),
+            "http://example.com/a/b/");
+
+        assert_eq!(HttpUri::new("http://example.com/a/b/../").unwrap().normalize().unwrap().to_string(),
+            "http://example.com/a/");
+    }
+
+    #[test]
+    fn test_http_uri_builder() {
+        assert_eq!(HttpUriBuilder::new()
+            .scheme(HttpScheme::Https)
+            .authority("example.com")
+            .path("/a/b")
+            .query("k=v")
+            .fragment("f")
+            .build()
+            .unwrap()
+            .to_string(), "https://example.com/a/b?k=v#f");
+
+        assert_eq!(HttpUriBuilder::new()
+            .scheme(HttpScheme::Http)
+            .authority("example.com")
+            .build()
+            .unwrap()
+            .to_string(), "http://example.com/");
+
+        assert!(HttpUriBuilder::new().authority("example.com").build().is_err());
+        assert!(HttpUriBuilder::new().scheme(HttpScheme::Http).build().is_err());
+        assert!(HttpUriBuilder::new()
+            .scheme(HttpScheme::Http)
+            .authority("example.com")
+            .path("a")
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let base = HttpUri::new("http://a/b/c/d;p?q").unwrap();
+
+        // RFC3986§5.4.1 normal examples (adapted: this crate always renders a path of
+        // at least "/", so bare-authority results like "http://g" become "http://g/").
+        assert_eq!(base.resolve("g").unwrap().to_string(), "http://a/b/c/g");
+        assert_eq!(base.resolve("./g").unwrap().to_string(), "http://a/b/c/g");
+        assert_eq!(base.resolve("g/").unwrap().to_string(), "http://a/b/c/g/");
+        assert_eq!(base.resolve("/g").unwrap().to_string(), "http://a/g");
+        assert_eq!(base.resolve("//g").unwrap().to_string(), "http://g/");
+        assert_eq!(base.resolve("//g/p").unwrap().to_string(), "http://g/p");
+        assert_eq!(base.resolve("?y").unwrap().to_string(), "http://a/b/c/d;p?y");
+        assert_eq!(base.resolve("g?y").unwrap().to_string(), "http://a/b/c/g?y");
+        assert_eq!(base.resolve("#s").unwrap().to_string(), "http://a/b/c/d;p?q#s");
+        assert_eq!(base.resolve("g#s").unwrap().to_string(), "http://a/b/c/g#s");
+        assert_eq!(base.resolve("g?y#s").unwrap().to_string(), "http://a/b/c/g?y#s");
+        assert_eq!(base.resolve(";x").unwrap().to_string(), "http://a/b/c/;x");
+        assert_eq!(base.resolve("g;x").unwrap().to_string(), "http://a/b/c/g;x");
+        assert_eq!(base.resolve("g;x?y#s").unwrap().to_string(), "http://a/b/c/g;x?y#s");
+        assert_eq!(base.resolve("").unwrap().to_string(), "http://a/b/c/d;p?q");
+        assert_eq!(base.resolve(".").unwrap().to_string(), "http://a/b/c/");
+        assert_eq!(base.resolve("./").unwrap().to_string(), "http://a/b/c/");
+        assert_eq!(base.resolve("..").unwrap().to_string(), "http://a/b/");
+        assert_eq!(base.resolve("../").unwrap().to_string(), "http://a/b/");
+        assert_eq!(base.resolve("../g").unwrap().to_string(), "http://a/b/g");
+        assert_eq!(base.resolve("../..").unwrap().to_string(), "http://a/");
+        assert_eq!(base.resolve("../../").unwrap().to_string(), "http://a/");
+        assert_eq!(base.resolve("../../g").unwrap().to_string(), "http://a/g");
+
+        // RFC3986§5.4.2 abnormal examples.
+        assert_eq!(base.resolve("../../../g").unwrap().to_string(), "http://a/g");
+        assert_eq!(base.resolve("../../../../g").unwrap().to_string(), "http://a/g");
+        assert_eq!(base.resolve("/./g").unwrap().to_string(), "http://a/g");
+        assert_eq!(base.resolve("/../g").unwrap().to_string(), "http://a/g");
+        assert_eq!(base.resolve("g.").unwrap().to_string(), "http://a/b/c/g.");
+        assert_eq!(base.resolve(".g").unwrap().to_string(), "http://a/b/c/.g");
+        assert_eq!(base.resolve("g..").unwrap().to_string(), "http://a/b/c/g..");
+        assert_eq!(base.resolve("..g").unwrap().to_string(), "http://a/b/c/..g");
+        assert_eq!(base.resolve("./../g").unwrap().to_string(), "http://a/b/g");
+        assert_eq!(base.resolve("./g/.").unwrap().to_string(), "http://a/b/c/g/");
+        assert_eq!(base.resolve("g/./h").unwrap().to_string(), "http://a/b/c/g/h");
+        assert_eq!(base.resolve("g/../h").unwrap().to_string(), "http://a/b/c/h");
+        assert_eq!(base.resolve("g;x=1/./y").unwrap().to_string(), "http://a/b/c/g;x=1/y");
+        assert_eq!(base.resolve("g;x=1/../y").unwrap().to_string(), "http://a/b/c/y");
+        assert_eq!(base.resolve("g?y/./x").unwrap().to_string(), "http://a/b/c/g?y/./x");
+        assert_eq!(base.resolve("g?y/../x").unwrap().to_string(), "http://a/b/c/g?y/../x");
+        assert_eq!(base.resolve("g#s/./x").unwrap().to_string(), "http://a/b/c/g#s/./x");
+        assert_eq!(base.resolve("g#s/../x").unwrap().to_string(), "http://a/b/c/g#s/../x");
+
+        // This crate's own vectors: a trailing dot-segment must leave a trailing slash.
+        assert_eq!(base.resolve("g/.").unwrap().to_string(), "http://a/b/c/g/");
+
+        assert_eq!(base.resolve("https://b/g").unwrap().to_string(), "https://b/g");
+
+        assert!(base.resolve("ftp://b/g").is_err());
+
+        assert_eq!(base.resolve("foo?next=http://evil.com").unwrap().to_string(),
+            "http://a/b/c/foo?next=http://evil.com");
+        assert_eq!(base.resolve("g#http://x").unwrap().to_string(),
+            "http://a/b/c/g#http://x");
+    }
 }